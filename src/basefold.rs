@@ -1,20 +1,24 @@
-use crate::field::FieldElement;
+use crate::diagnostics::ProofFailureReportBuilder;
+use crate::error::{CryptoError, VerificationCheck};
+use crate::field::{FieldElement, PrimeField};
+use crate::poseidon::Poseidon;
 use crate::reed_muller::ReedMullerCode;
 use rand::Rng;
 use std::collections::HashMap;
 
-pub struct BasefoldProtocol {
-    pub code_family: Vec<ReedMullerCode>,
-    pub t_vectors: Vec<Vec<FieldElement>>,
-    commitment_randomness: Vec<FieldElement>,
-    hash_table: HashMap<Vec<FieldElement>, Vec<FieldElement>>,
+pub struct BasefoldProtocol<F: PrimeField> {
+    pub code_family: Vec<ReedMullerCode<F>>,
+    pub t_vectors: Vec<Vec<FieldElement<F>>>,
+    commitment_randomness: Vec<FieldElement<F>>,
+    hash_table: HashMap<Vec<FieldElement<F>>, Vec<FieldElement<F>>>,
+    poseidon: Poseidon<F>,
 }
 
-impl BasefoldProtocol {
-    pub fn new(code_family: Vec<ReedMullerCode>, t_vectors: Vec<Vec<FieldElement>>) -> Self {
+impl<F: PrimeField> BasefoldProtocol<F> {
+    pub fn new(code_family: Vec<ReedMullerCode<F>>, t_vectors: Vec<Vec<FieldElement<F>>>) -> Self {
         let mut rng = rand::thread_rng();
-        let commitment_randomness: Vec<FieldElement> = (0..code_family.len())
-            .map(|_| FieldElement::new(rng.gen()))
+        let commitment_randomness: Vec<FieldElement<F>> = (0..code_family.len())
+            .map(|_| FieldElement::new(rng.gen::<u128>() % F::MODULUS).unwrap())
             .collect();
 
         BasefoldProtocol {
@@ -22,10 +26,11 @@ impl BasefoldProtocol {
             t_vectors,
             commitment_randomness,
             hash_table: HashMap::new(),
+            poseidon: Poseidon::new(),
         }
     }
 
-    pub fn commit(&self, message: &[FieldElement]) -> Vec<Vec<FieldElement>> {
+    pub fn commit(&self, message: &[FieldElement<F>]) -> Vec<Vec<FieldElement<F>>> {
         let mut oracles = Vec::new();
         let mut current = message.to_vec();
         oracles.push(current.clone());
@@ -42,37 +47,42 @@ impl BasefoldProtocol {
 
     fn fold_with_merkle(
         &self,
-        v: &[FieldElement],
-        t: &[FieldElement],
-        r: FieldElement,
-    ) -> Vec<FieldElement> {
+        v: &[FieldElement<F>],
+        t: &[FieldElement<F>],
+        r: FieldElement<F>,
+    ) -> Vec<FieldElement<F>> {
         let n = v.len();
         assert_eq!(n % 2, 0);
         let mut folded = Vec::with_capacity(n / 2);
         let mut merkle_tree = self.build_merkle_tree(v);
 
-        for j in (0..n).step_by(2) {
+        // Invert every pair's denominator `t_{j+1} - t_j` in one batch via
+        // Montgomery's trick instead of one extended-Euclid inversion per
+        // pair.
+        let mut denominator_inverses: Vec<FieldElement<F>> =
+            (0..n).step_by(2).map(|j| t[j + 1] - t[j]).collect();
+        FieldElement::batch_inverse(&mut denominator_inverses);
+
+        for (pair_idx, j) in (0..n).step_by(2).enumerate() {
             let t_j = t[j];
-            let t_j1 = t[j + 1];
             let v_j = v[j];
             let v_j1 = v[j + 1];
 
             let numerator = v_j1 - v_j;
-            let denominator = t_j1 - t_j;
-            let slope = numerator * denominator.inverse();
+            let slope = numerator * denominator_inverses[pair_idx];
             let value_at_r = slope * (r - t_j) + v_j;
-            
+
             // Add Merkle proof
-            let proof = self.generate_merkle_proof(&merkle_tree, j / 2);
+            let proof = self.generate_merkle_proof(&merkle_tree, pair_idx);
             self.hash_table.insert(proof, vec![value_at_r]);
-            
+
             folded.push(value_at_r);
         }
 
         folded
     }
 
-    fn build_merkle_tree(&self, values: &[FieldElement]) -> Vec<Vec<FieldElement>> {
+    fn build_merkle_tree(&self, values: &[FieldElement<F>]) -> Vec<Vec<FieldElement<F>>> {
         let mut tree = Vec::new();
         let mut current_level = values.to_vec();
         tree.push(current_level.clone());
@@ -94,19 +104,17 @@ impl BasefoldProtocol {
         tree
     }
 
-    fn hash_pair(&self, left: &FieldElement, right: &FieldElement) -> FieldElement {
-        // Pedersen commitment-based hashing
-        let mut rng = rand::thread_rng();
-        let r: u128 = rng.gen();
-        let h = FieldElement::new(r);
-        left * h + right
+    fn hash_pair(&self, left: &FieldElement<F>, right: &FieldElement<F>) -> FieldElement<F> {
+        // Algebraic (Poseidon) compression, deterministic in left/right so
+        // `build_merkle_tree`/`verify_merkle_proof` agree on the same root.
+        self.poseidon.hash_pair(*left, *right)
     }
 
     fn generate_merkle_proof(
         &self,
-        tree: &[Vec<FieldElement>],
+        tree: &[Vec<FieldElement<F>>],
         index: usize,
-    ) -> Vec<FieldElement> {
+    ) -> Vec<FieldElement<F>> {
         let mut proof = Vec::new();
         let mut current_idx = index;
 
@@ -129,9 +137,9 @@ impl BasefoldProtocol {
 
     pub fn verify_merkle_proof(
         &self,
-        root: &FieldElement,
-        value: &FieldElement,
-        proof: &[FieldElement],
+        root: &FieldElement<F>,
+        value: &FieldElement<F>,
+        proof: &[FieldElement<F>],
         index: usize,
     ) -> bool {
         let mut current = *value;
@@ -149,25 +157,43 @@ impl BasefoldProtocol {
         &current == root
     }
 
-    pub fn query(&self, oracles: &[Vec<FieldElement>], lambda: usize) -> bool {
+    pub fn query(&self, oracles: &[Vec<FieldElement<F>>], lambda: usize) -> bool {
+        self.query_with_report(oracles, lambda).is_ok()
+    }
+
+    /// Like [`Self::query`], but instead of collapsing to a bare `bool`,
+    /// accumulates every failing check across all `lambda` rounds into a
+    /// [`ProofFailureReport`](crate::diagnostics::ProofFailureReport) so a
+    /// caller can see exactly which round/check/constraint disagreed and
+    /// with what values, instead of only "verification failed".
+    pub fn query_with_report(
+        &self,
+        oracles: &[Vec<FieldElement<F>>],
+        lambda: usize,
+    ) -> Result<(), CryptoError> {
         let mut rng = rand::thread_rng();
-        
-        for _ in 0..lambda {
+        let mut report = ProofFailureReportBuilder::new();
+
+        for round in 0..lambda {
             let d = self.code_family.len() - 1;
             let mut mu = rng.gen_range(0..oracles[d].len());
             if mu % 2 != 0 {
                 mu -= 1;
             }
 
-            if !self.verify_query_path_with_merkle(oracles, mu) {
-                return false;
-            }
+            self.verify_query_path_with_report(oracles, mu, round, &mut report);
         }
 
-        true
+        if report.is_empty() {
+            Ok(())
+        } else {
+            Err(CryptoError::VerificationError {
+                report: report.build(),
+            })
+        }
     }
 
-    fn verify_query_path_with_merkle(&self, oracles: &[Vec<FieldElement>], mut mu: usize) -> bool {
+    fn verify_query_path_with_merkle(&self, oracles: &[Vec<FieldElement<F>>], mut mu: usize) -> bool {
         for i in (0..self.code_family.len()).rev() {
             let pi_i_plus1 = &oracles[i + 1];
             let pi_i = &oracles[i];
@@ -196,14 +222,73 @@ impl BasefoldProtocol {
         true
     }
 
+    /// Same walk as [`Self::verify_query_path_with_merkle`], but keeps going
+    /// after a mismatch instead of short-circuiting, recording each failure
+    /// (with its fold-family index as the "constraint" and the offending
+    /// values) into `report` under the current `round`.
+    fn verify_query_path_with_report(
+        &self,
+        oracles: &[Vec<FieldElement<F>>],
+        mut mu: usize,
+        round: usize,
+        report: &mut ProofFailureReportBuilder,
+    ) {
+        for i in (0..self.code_family.len()).rev() {
+            let pi_i_plus1 = &oracles[i + 1];
+            let pi_i = &oracles[i];
+            let t_i = &self.t_vectors[i];
+            let r = self.commitment_randomness[i];
+
+            if let Some(proof) = self.hash_table.get(&pi_i_plus1[mu..mu + 2].to_vec()) {
+                if !self.verify_merkle_proof(&pi_i[mu / 2], &proof[0], &proof[1..], mu / 2) {
+                    report.record(
+                        round,
+                        VerificationCheck::CommitmentOpening,
+                        Some(i),
+                        Some(pi_i[mu / 2].value()),
+                        Some(proof[0].value()),
+                    );
+                }
+            }
+
+            let (consistent, expected, actual) =
+                self.verify_fold_at_point_detailed(pi_i, pi_i_plus1, t_i, r, mu);
+            if !consistent {
+                report.record(
+                    round,
+                    VerificationCheck::FoldConsistency,
+                    Some(i),
+                    Some(expected.value()),
+                    Some(actual.value()),
+                );
+            }
+
+            mu /= 2;
+        }
+    }
+
     fn verify_fold_at_point(
         &self,
-        pi_i: &[FieldElement],
-        pi_i_plus1: &[FieldElement],
-        t_i: &[FieldElement],
-        r: FieldElement,
+        pi_i: &[FieldElement<F>],
+        pi_i_plus1: &[FieldElement<F>],
+        t_i: &[FieldElement<F>],
+        r: FieldElement<F>,
         mu: usize,
     ) -> bool {
+        self.verify_fold_at_point_detailed(pi_i, pi_i_plus1, t_i, r, mu).0
+    }
+
+    /// Computes both sides of the fold-consistency check instead of just
+    /// their equality, so [`Self::verify_query_path_with_report`] can report
+    /// the expected vs. actual value on a mismatch rather than only `false`.
+    fn verify_fold_at_point_detailed(
+        &self,
+        pi_i: &[FieldElement<F>],
+        pi_i_plus1: &[FieldElement<F>],
+        t_i: &[FieldElement<F>],
+        r: FieldElement<F>,
+        mu: usize,
+    ) -> (bool, FieldElement<F>, FieldElement<F>) {
         let v_mu = pi_i_plus1[mu];
         let v_mu_plus1 = pi_i_plus1[mu + 1];
         let t_mu = t_i[mu];
@@ -211,9 +296,10 @@ impl BasefoldProtocol {
 
         let numerator = v_mu_plus1 - v_mu;
         let denominator = t_mu_plus1 - t_mu;
-        let slope = numerator * denominator.inverse();
+        let slope = numerator * denominator.inverse().unwrap();
         let expected = slope * (r - t_mu) + v_mu;
+        let actual = pi_i[mu / 2];
 
-        pi_i[mu / 2] == expected
+        (actual == expected, expected, actual)
     }
 }
\ No newline at end of file
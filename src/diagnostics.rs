@@ -0,0 +1,128 @@
+use crate::error::VerificationCheck;
+use std::fmt;
+
+/// One verification check that failed during a single interactive-protocol
+/// round, with enough detail to debug exactly what went wrong instead of a
+/// flat string. `expected`/`actual` are recorded as raw field values
+/// (`u128`) rather than `FieldElement<F>` so that
+/// `ProofFailureReport` - and therefore `CryptoError` - doesn't need to carry
+/// the `PrimeField` type parameter that the rest of the crate does.
+#[derive(Debug, Clone)]
+pub struct FailedCheck {
+    pub round: usize,
+    pub check: VerificationCheck,
+    pub constraint_index: Option<usize>,
+    pub expected: Option<u128>,
+    pub actual: Option<u128>,
+}
+
+impl fmt::Display for FailedCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "round {}: {}", self.round, self.check)?;
+        if let Some(idx) = self.constraint_index {
+            write!(f, " (constraint/gate #{})", idx)?;
+        }
+        if let (Some(expected), Some(actual)) = (self.expected, self.actual) {
+            write!(f, ", expected {}, got {}", expected, actual)?;
+        }
+        Ok(())
+    }
+}
+
+/// Accumulates every [`FailedCheck`] observed while verifying a proof, so
+/// callers and tests can assert on the precise failure mode(s) - which
+/// [`VerificationCheck::CommitmentOpening`] or
+/// [`VerificationCheck::FoldConsistency`] mismatched, at which
+/// constraint/gate index, with what expected/actual values - rather than a
+/// single opaque string. Basefold's verifier never derives
+/// `commitment_randomness` from a Fiat-Shamir transcript hash (it's sampled
+/// directly, so there's nothing to recompute), and no degree-bound check is
+/// performed, so neither of those is among the recorded checks.
+#[derive(Debug, Clone, Default)]
+pub struct ProofFailureReport {
+    failures: Vec<FailedCheck>,
+}
+
+impl ProofFailureReport {
+    pub fn is_empty(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    pub fn checks(&self) -> &[FailedCheck] {
+        &self.failures
+    }
+
+    /// The constraint/gate index of the first recorded failure that has one.
+    pub fn failing_constraint(&self) -> Option<usize> {
+        self.failures.iter().find_map(|f| f.constraint_index)
+    }
+
+    /// The expected field value of the first recorded failure that has one.
+    pub fn expected(&self) -> Option<u128> {
+        self.failures.iter().find_map(|f| f.expected)
+    }
+
+    /// The actual (observed) field value of the first recorded failure that
+    /// has one.
+    pub fn actual(&self) -> Option<u128> {
+        self.failures.iter().find_map(|f| f.actual)
+    }
+}
+
+impl fmt::Display for ProofFailureReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.failures.is_empty() {
+            return write!(f, "no verification failures recorded");
+        }
+        writeln!(f, "{} verification check(s) failed:", self.failures.len())?;
+        for (i, failure) in self.failures.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "  - {}", failure)?;
+        }
+        Ok(())
+    }
+}
+
+/// Builder threaded through the verifier's per-round logic, so each round
+/// can record whatever checks it performs without the verifier needing to
+/// bail out (and lose later diagnostics) on the first mismatch.
+#[derive(Debug, Default)]
+pub struct ProofFailureReportBuilder {
+    failures: Vec<FailedCheck>,
+}
+
+impl ProofFailureReportBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    pub fn record(
+        &mut self,
+        round: usize,
+        check: VerificationCheck,
+        constraint_index: Option<usize>,
+        expected: Option<u128>,
+        actual: Option<u128>,
+    ) -> &mut Self {
+        self.failures.push(FailedCheck {
+            round,
+            check,
+            constraint_index,
+            expected,
+            actual,
+        });
+        self
+    }
+
+    pub fn build(self) -> ProofFailureReport {
+        ProofFailureReport {
+            failures: self.failures,
+        }
+    }
+}
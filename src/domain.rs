@@ -0,0 +1,233 @@
+use crate::error::CryptoError;
+use crate::field::{FieldElement, PrimeField};
+
+/// A size-`n` multiplicative subgroup of `F*`, for `n` a power of two,
+/// together with the constants needed to FFT/IFFT across it. Turns
+/// "evaluate this degree-below-`n` polynomial at `n` points" / "interpolate these
+/// `n` evaluations" from an O(n^2) dense operation into an O(n log n)
+/// radix-2 Cooley-Tukey pass.
+pub struct EvaluationDomain<F: PrimeField> {
+    pub size: usize,
+    pub log_size: u32,
+    pub omega: FieldElement<F>,
+    pub omega_inv: FieldElement<F>,
+    pub size_inv: FieldElement<F>,
+    /// A generator of `F*`, used by [`Self::coset_fft`]/[`Self::coset_ifft`]
+    /// to shift the subgroup `H` (which `omega` generates) into the coset
+    /// `generator * H` - points disjoint from `H` itself, which is what lets
+    /// a low-degree extension be evaluated somewhere distinguishable from
+    /// the original evaluation domain.
+    pub generator: FieldElement<F>,
+    pub generator_inv: FieldElement<F>,
+}
+
+impl<F: PrimeField> EvaluationDomain<F> {
+    /// Builds the domain for a given `size`. `size` must be a power of two
+    /// no larger than `2^F::TWO_ADICITY`, the largest power-of-two subgroup
+    /// the field actually has a root of unity for.
+    pub fn new(size: usize) -> Result<Self, CryptoError> {
+        if size == 0 || !size.is_power_of_two() {
+            return Err(CryptoError::InvalidParameters {
+                param: "size",
+                expected: "a power of two".to_string(),
+                got: size.to_string(),
+            });
+        }
+        let log_size = size.trailing_zeros();
+        if log_size > F::TWO_ADICITY {
+            return Err(CryptoError::InvalidParameters {
+                param: "size",
+                expected: format!("at most 2^{}", F::TWO_ADICITY),
+                got: format!("2^{}", log_size),
+            });
+        }
+
+        // `F::ROOT_OF_UNITY` generates the full `2^TWO_ADICITY` subgroup;
+        // squaring it down shrinks that to a primitive `2^log_size`-th root.
+        let mut omega = FieldElement::<F>::new(F::ROOT_OF_UNITY).unwrap();
+        for _ in log_size..F::TWO_ADICITY {
+            omega = omega * omega;
+        }
+
+        let omega_inv = omega.inverse()?;
+        let size_inv = FieldElement::<F>::new(size as u128).unwrap().inverse()?;
+        let generator = FieldElement::<F>::new(F::MULTIPLICATIVE_GENERATOR).unwrap();
+        let generator_inv = generator.inverse()?;
+
+        Ok(Self {
+            size,
+            log_size,
+            omega,
+            omega_inv,
+            size_inv,
+            generator,
+            generator_inv,
+        })
+    }
+
+    /// In-place evaluation: turns `n` polynomial coefficients into their `n`
+    /// evaluations over the domain.
+    pub fn fft(&self, coeffs: &mut [FieldElement<F>]) {
+        debug_assert_eq!(coeffs.len(), self.size);
+        Self::radix2_butterfly(coeffs, self.omega);
+    }
+
+    /// In-place interpolation: the inverse of [`Self::fft`].
+    pub fn ifft(&self, evals: &mut [FieldElement<F>]) {
+        debug_assert_eq!(evals.len(), self.size);
+        Self::radix2_butterfly(evals, self.omega_inv);
+        for value in evals.iter_mut() {
+            *value = *value * self.size_inv;
+        }
+    }
+
+    /// Like [`Self::fft`], but evaluates over the coset `generator * H`
+    /// instead of the subgroup `H` itself: scaling `coeffs[i]` by
+    /// `generator^i` before the transform shifts every evaluation point
+    /// from `omega^i` to `generator * omega^i`.
+    pub fn coset_fft(&self, coeffs: &mut [FieldElement<F>]) {
+        debug_assert_eq!(coeffs.len(), self.size);
+        let mut shift = FieldElement::<F>::one();
+        for c in coeffs.iter_mut() {
+            *c = *c * shift;
+            shift = shift * self.generator;
+        }
+        self.fft(coeffs);
+    }
+
+    /// In-place interpolation: the inverse of [`Self::coset_fft`].
+    pub fn coset_ifft(&self, evals: &mut [FieldElement<F>]) {
+        debug_assert_eq!(evals.len(), self.size);
+        self.ifft(evals);
+        let mut shift = FieldElement::<F>::one();
+        for c in evals.iter_mut() {
+            *c = *c * shift;
+            shift = shift * self.generator_inv;
+        }
+    }
+
+    /// Shared radix-2 Cooley-Tukey butterfly: bit-reverse the input, then
+    /// repeatedly combine pairs within doubling-size blocks using twiddles
+    /// `omega^j`. Running it with `omega` gives the forward transform, and
+    /// with `omega_inv` (plus the `size_inv` scaling in [`Self::ifft`]) gives
+    /// the inverse.
+    fn radix2_butterfly(a: &mut [FieldElement<F>], omega: FieldElement<F>) {
+        let n = a.len();
+        if n <= 1 {
+            return;
+        }
+
+        Self::bit_reverse_permute(a);
+
+        let mut len = 2;
+        while len <= n {
+            let half = len / 2;
+            let twiddle_step = omega.pow((n / len) as u128);
+            for block in (0..n).step_by(len) {
+                let mut twiddle = FieldElement::<F>::one();
+                for j in 0..half {
+                    let u = a[block + j];
+                    let v = a[block + j + half] * twiddle;
+                    a[block + j] = u + v;
+                    a[block + j + half] = u - v;
+                    twiddle = twiddle * twiddle_step;
+                }
+            }
+            len *= 2;
+        }
+    }
+
+    fn bit_reverse_permute(a: &mut [FieldElement<F>]) {
+        let n = a.len();
+        let bits = n.trailing_zeros();
+        for i in 0..n {
+            let j = i.reverse_bits() >> (usize::BITS - bits);
+            if i < j {
+                a.swap(i, j);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::declare_prime_field;
+
+    declare_prime_field!(DomainTestField, 1009u128, 4, 11, 179);
+    type Fe = FieldElement<DomainTestField>;
+
+    /// `O(n^2)` reference evaluation: `evals[k] = sum_j coeffs[j] * omega^(j*k)`.
+    fn naive_dft(coeffs: &[Fe], omega: Fe) -> Vec<Fe> {
+        let n = coeffs.len();
+        (0..n)
+            .map(|k| {
+                (0..n).fold(Fe::zero(), |acc, j| {
+                    acc + coeffs[j] * omega.pow((j * k) as u128)
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fft_matches_naive_dft() {
+        let domain = EvaluationDomain::<DomainTestField>::new(8).unwrap();
+        let coeffs: Vec<Fe> = (0..8).map(|i| Fe::new(i as u128).unwrap()).collect();
+
+        let mut via_fft = coeffs.clone();
+        domain.fft(&mut via_fft);
+
+        let via_naive = naive_dft(&coeffs, domain.omega);
+
+        assert_eq!(via_fft, via_naive);
+    }
+
+    #[test]
+    fn ifft_inverts_fft() {
+        let domain = EvaluationDomain::<DomainTestField>::new(8).unwrap();
+        let coeffs: Vec<Fe> = (0..8).map(|i| Fe::new((i * 3 + 1) as u128).unwrap()).collect();
+
+        let mut roundtrip = coeffs.clone();
+        domain.fft(&mut roundtrip);
+        domain.ifft(&mut roundtrip);
+
+        assert_eq!(roundtrip, coeffs);
+    }
+
+    #[test]
+    fn new_rejects_non_power_of_two() {
+        assert!(EvaluationDomain::<DomainTestField>::new(6).is_err());
+    }
+
+    #[test]
+    fn coset_ifft_inverts_coset_fft() {
+        let domain = EvaluationDomain::<DomainTestField>::new(8).unwrap();
+        let coeffs: Vec<Fe> = (0..8).map(|i| Fe::new((i * 3 + 1) as u128).unwrap()).collect();
+
+        let mut roundtrip = coeffs.clone();
+        domain.coset_fft(&mut roundtrip);
+        domain.coset_ifft(&mut roundtrip);
+
+        assert_eq!(roundtrip, coeffs);
+    }
+
+    #[test]
+    fn coset_fft_evaluates_at_shifted_points() {
+        let domain = EvaluationDomain::<DomainTestField>::new(8).unwrap();
+        let coeffs: Vec<Fe> = (0..8).map(|i| Fe::new(i as u128).unwrap()).collect();
+
+        let mut via_coset_fft = coeffs.clone();
+        domain.coset_fft(&mut via_coset_fft);
+
+        // `coset_fft` must evaluate at `generator * omega^k`, not at
+        // `omega^k` like the plain subgroup `fft` does.
+        let via_naive: Vec<Fe> = (0..8)
+            .map(|k| {
+                let point = domain.generator * domain.omega.pow(k as u128);
+                (0..8).fold(Fe::zero(), |acc, j| acc + coeffs[j] * point.pow(j as u128))
+            })
+            .collect();
+
+        assert_eq!(via_coset_fft, via_naive);
+    }
+}
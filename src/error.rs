@@ -1,23 +1,81 @@
+use std::fmt;
+
+use crate::diagnostics::ProofFailureReport;
+
+/// Which step of proof verification failed. Matching on this instead of
+/// parsing `CryptoError`'s `Display` string lets callers implement
+/// retry/fallback logic (e.g. only retry on `FoldConsistency`, never on
+/// `CommitmentOpening`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerificationCheck {
+    /// A Merkle opening did not match the claimed commitment.
+    CommitmentOpening,
+    /// A folded value did not match the expected interpolation at the
+    /// verifier's challenge point.
+    FoldConsistency,
+}
+
+impl fmt::Display for VerificationCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            Self::CommitmentOpening => "commitment opening mismatch",
+            Self::FoldConsistency => "fold consistency mismatch",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+/// Which stage of proof generation failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofStage {
+    Commitment,
+    Folding,
+    Query,
+    Opening,
+}
+
+impl fmt::Display for ProofStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Commitment => "commitment",
+            Self::Folding => "folding",
+            Self::Query => "query",
+            Self::Opening => "opening",
+        };
+        write!(f, "{}", name)
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum CryptoError {
     #[error("Field arithmetic error: {0}")]
     FieldError(#[from] crate::field::FieldError),
-    
-    #[error("Invalid parameters: {0}")]
-    InvalidParameters(String),
-    
-    #[error("Protocol verification failed: {0}")]
-    VerificationError(String),
-    
+
+    #[error("Invalid parameter `{param}`: expected {expected}, got {got}")]
+    InvalidParameters {
+        param: &'static str,
+        expected: String,
+        got: String,
+    },
+
+    #[error("Protocol verification failed: {report}")]
+    VerificationError { report: ProofFailureReport },
+
+    // No `Io(#[from] std::io::Error)` / `Serde(#[from] serde_json::Error)` /
+    // `Hex(#[from] hex::FromHexError)` chain here: nothing in this crate
+    // does file IO, JSON, or hex (de)serialization of proofs or transcripts
+    // yet, so there is no `?` call site for them to serve. Add the
+    // `#[from]` variants back once proof/transcript (de)serialization
+    // exists to actually use them.
     #[error("Encoding error: {0}")]
     EncodingError(String),
-    
+
     #[error("Decoding error: {0}")]
     DecodingError(String),
-    
-    #[error("Proof generation failed: {0}")]
-    ProofError(String),
-    
+
+    #[error("Proof generation failed at the {stage} stage")]
+    ProofError { stage: ProofStage },
+
     #[error("System error: {0}")]
     SystemError(String),
-}
\ No newline at end of file
+}
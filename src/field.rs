@@ -1,15 +1,8 @@
 use std::ops::{Add, Sub, Mul, Div};
-use std::fmt::{Debug, Display};
-use num_bigint::BigUint;
-use num_traits::{One, Zero};
+use std::fmt::Debug;
+use std::marker::PhantomData;
 use thiserror::Error;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub struct FieldElement {
-    value: u128,
-    modulus: u128,
-}
-
 #[derive(Error, Debug)]
 pub enum FieldError {
     #[error("Division by zero")]
@@ -20,23 +13,153 @@ pub enum FieldError {
     ValueExceedsModulus,
 }
 
-impl FieldElement {
-    pub fn new(value: u128, modulus: u128) -> Result<Self, FieldError> {
-        if modulus <= 1 {
+/// `R = 2^128`, the Montgomery radix [`FieldElement`] stores its internal
+/// representation with respect to.
+///
+/// `u128` wraps at exactly this value, so every `wrapping_*` op below that
+/// operates on a `u128` is implicitly "mod R" for free.
+const MONTGOMERY_R_BITS: u32 = 128;
+
+/// Compile-time description of a prime field: the modulus and its
+/// Montgomery/NTT parameters live on the type instead of on every
+/// [`FieldElement`], so two elements of different fields simply can't be
+/// added or multiplied together - the compiler rejects it instead of an
+/// `assert_eq!` panicking at runtime.
+///
+/// Implementors are expected to be declared via [`declare_prime_field!`]
+/// rather than by hand.
+pub trait PrimeField: Copy + Clone + Debug + Eq + std::hash::Hash + 'static {
+    /// The field's prime modulus.
+    const MODULUS: u128;
+    /// Montgomery radix reduced mod the modulus, `R = 2^128 mod MODULUS`.
+    const R: u128;
+    /// `R^2 mod MODULUS`, used to carry ordinary residues into Montgomery form.
+    const R2: u128;
+    /// `-MODULUS^-1 mod R`, the constant REDC multiplies by.
+    const N_PRIME: u128;
+    /// The largest `k` such that `2^k` divides `MODULUS - 1`.
+    const TWO_ADICITY: u32;
+    /// A generator of the full multiplicative group `F*`.
+    const MULTIPLICATIVE_GENERATOR: u128;
+    /// A primitive `2^TWO_ADICITY`-th root of unity, i.e.
+    /// `MULTIPLICATIVE_GENERATOR ^ ((MODULUS - 1) / 2^TWO_ADICITY)`.
+    const ROOT_OF_UNITY: u128;
+}
+
+/// Russian-peasant modular multiplication: computes `a * b mod m` without the
+/// 256-bit intermediate a naive `a * b` would need, so it stays valid inside
+/// `const fn` (no heap allocation there).
+const fn const_mulmod(a: u128, b: u128, m: u128) -> u128 {
+    let mut a = a % m;
+    let mut b = b;
+    let mut result: u128 = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = (result + a) % m;
+        }
+        a = (a * 2) % m;
+        b >>= 1;
+    }
+    result
+}
+
+/// `2^exponent mod modulus`, via repeated doubling so it never forms a
+/// literal `2^128` (which would overflow `u128`).
+const fn const_pow2_mod(exponent: u32, modulus: u128) -> u128 {
+    let mut result = 1u128 % modulus;
+    let mut e = 0;
+    while e < exponent {
+        result = const_mulmod(result, 2, modulus);
+        e += 1;
+    }
+    result
+}
+
+/// `R = 2^128 mod modulus`, for use in [`declare_prime_field!`].
+pub const fn compute_r(modulus: u128) -> u128 {
+    const_pow2_mod(MONTGOMERY_R_BITS, modulus)
+}
+
+/// `R^2 mod modulus`, for use in [`declare_prime_field!`].
+pub const fn compute_r2(modulus: u128) -> u128 {
+    let r = compute_r(modulus);
+    const_mulmod(r, r, modulus)
+}
+
+/// `-modulus^-1 mod R` via Newton's iteration on the low word: each round
+/// doubles the number of correct bits, and `modulus` (being odd) is already
+/// its own inverse mod 2, so seven rounds converge across all 128 bits of `R`.
+pub const fn compute_n_prime(modulus: u128) -> u128 {
+    let mut x = modulus;
+    let mut i = 0;
+    while i < 7 {
+        x = x.wrapping_mul(2u128.wrapping_sub(modulus.wrapping_mul(x)));
+        i += 1;
+    }
+    x.wrapping_neg()
+}
+
+/// Declares a zero-sized field-parameter type implementing [`PrimeField`]
+/// from a prime literal. `R`, `R2`, and `N_PRIME` are derived automatically;
+/// `two_adicity`, `generator`, and `root_of_unity` must be supplied since
+/// they come from factoring `modulus - 1`, which isn't practical to do in a
+/// `const fn`.
+#[macro_export]
+macro_rules! declare_prime_field {
+    ($name:ident, $modulus:expr, $two_adicity:expr, $generator:expr, $root_of_unity:expr) => {
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub struct $name;
+
+        impl $crate::field::PrimeField for $name {
+            const MODULUS: u128 = $modulus;
+            const R: u128 = $crate::field::compute_r($modulus);
+            const R2: u128 = $crate::field::compute_r2($modulus);
+            const N_PRIME: u128 = $crate::field::compute_n_prime($modulus);
+            const TWO_ADICITY: u32 = $two_adicity;
+            const MULTIPLICATIVE_GENERATOR: u128 = $generator;
+            const ROOT_OF_UNITY: u128 = $root_of_unity;
+        }
+    };
+}
+
+/// A field element, stored internally in whatever representation makes
+/// repeated arithmetic on it cheap: the ordinary residue when `F::MODULUS`
+/// is even (Montgomery reduction doesn't apply there), or its Montgomery
+/// form `a * R mod N` when `F::MODULUS` is odd. Converting happens once, in
+/// [`Self::new`] and [`Self::value`] - every other op (`Add`, `Sub`, `Mul`,
+/// `pow`, ...) operates directly on whichever representation is already
+/// resident, so a chain of multiplies costs one REDC each instead of three
+/// conversions plus a REDC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FieldElement<F: PrimeField> {
+    value: u128,
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField> FieldElement<F> {
+    pub fn new(value: u128) -> Result<Self, FieldError> {
+        if F::MODULUS <= 1 {
             return Err(FieldError::InvalidModulus);
         }
-        if value >= modulus {
+        if value >= F::MODULUS {
             return Err(FieldError::ValueExceedsModulus);
         }
-        Ok(Self { value, modulus })
+        Ok(Self { value: Self::to_internal(value), _field: PhantomData })
     }
 
-    pub fn zero(modulus: u128) -> Result<Self, FieldError> {
-        Self::new(0, modulus)
+    pub fn zero() -> Self {
+        Self { value: 0, _field: PhantomData }
     }
 
-    pub fn one(modulus: u128) -> Result<Self, FieldError> {
-        Self::new(1, modulus)
+    pub fn one() -> Self {
+        let value = if Self::supports_montgomery() { F::R } else { 1 % F::MODULUS };
+        Self { value, _field: PhantomData }
+    }
+
+    /// The element's ordinary residue mod `F::MODULUS`, converting out of
+    /// the internal representation.
+    pub fn value(&self) -> u128 {
+        Self::from_internal(self.value)
     }
 
     pub fn inverse(&self) -> Result<Self, FieldError> {
@@ -44,7 +167,10 @@ impl FieldElement {
             return Err(FieldError::DivisionByZero);
         }
 
-        let (mut s, mut t, mut r) = (0i128, 1i128, self.modulus as i128);
+        // Extended Euclid inverts whatever `self.value` actually holds,
+        // which is `self`'s internal representation, not necessarily its
+        // ordinary residue.
+        let (mut s, mut t, mut r) = (0i128, 1i128, F::MODULUS as i128);
         let (mut old_s, mut old_t, mut old_r) = (1i128, 0i128, self.value as i128);
 
         while r != 0 {
@@ -54,21 +180,32 @@ impl FieldElement {
             (old_t, t) = (t, old_t - quotient * t);
         }
 
-        let mut result = old_s as u128;
-        if result >= self.modulus {
-            result %= self.modulus;
+        let mut inverse_of_internal = old_s as u128;
+        if inverse_of_internal >= F::MODULUS {
+            inverse_of_internal %= F::MODULUS;
         }
         if old_s < 0 {
-            result = self.modulus - ((-old_s as u128) % self.modulus);
+            inverse_of_internal = F::MODULUS - ((-old_s as u128) % F::MODULUS);
         }
-        
-        Self::new(result, self.modulus)
+
+        // When the modulus is odd, `self.value` is Montgomery form `a*R mod
+        // N`, so the inverse just computed is `a^-1 * R^-1 mod N` rather
+        // than `a^-1`. Each `to_internal` pass (a REDC against `R^2`)
+        // multiplies by `R`: once to land on the ordinary residue `a^-1`,
+        // once more to land back in Montgomery form `a^-1 * R mod N` -
+        // matching whatever representation `self.value` is already in.
+        let value = if Self::supports_montgomery() {
+            Self::to_internal(Self::to_internal(inverse_of_internal))
+        } else {
+            inverse_of_internal
+        };
+
+        Ok(Self { value, _field: PhantomData })
     }
 
     pub fn pow(&self, mut exponent: u128) -> Self {
-        let mut result = Self { value: 1, modulus: self.modulus };
+        let mut result = Self::one();
         let mut base = *self;
-
         while exponent > 0 {
             if exponent & 1 == 1 {
                 result = result * base;
@@ -79,36 +216,108 @@ impl FieldElement {
         result
     }
 
-    pub fn sqrt(&self) -> Option<Self> {
-        if self.value == 0 {
-            return Some(*self);
+    /// Montgomery reduction is only valid when `gcd(modulus, R) == 1`.
+    /// `R = 2^128` is a power of two, so that holds exactly when `modulus`
+    /// is odd.
+    fn supports_montgomery() -> bool {
+        F::MODULUS % 2 == 1
+    }
+
+    /// `a * b` as an exact 256-bit product, returned as `(hi, lo)` such that
+    /// the product equals `hi * 2^128 + lo`. Schoolbook-multiplies the two
+    /// 64-bit halves of each operand and combines the four partial products
+    /// with carries, so [`Self::redc`] never needs an intermediate wider
+    /// than `u128` - and therefore never heap-allocates.
+    fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+        let a_lo = a as u64 as u128;
+        let a_hi = a >> 64;
+        let b_lo = b as u64 as u128;
+        let b_hi = b >> 64;
+
+        let lo_lo = a_lo * b_lo;
+        let lo_hi = a_lo * b_hi;
+        let hi_lo = a_hi * b_lo;
+        let hi_hi = a_hi * b_hi;
+
+        let (mid, mid_overflow) = lo_hi.overflowing_add(hi_lo);
+        let mid_lo = mid << 64;
+        let mid_hi = mid >> 64;
+
+        let (lo, lo_overflow) = lo_lo.overflowing_add(mid_lo);
+        let hi = hi_hi
+            .wrapping_add(mid_hi)
+            .wrapping_add((mid_overflow as u128) << 64)
+            .wrapping_add(lo_overflow as u128);
+
+        (hi, lo)
+    }
+
+    /// Montgomery reduction (REDC): given a 256-bit value `hi * 2^128 +
+    /// lo`, returns `(hi * 2^128 + lo) * R^-1 mod N`. Every Montgomery-form
+    /// conversion and multiply below bottoms out in this one primitive.
+    fn redc(lo: u128, hi: u128) -> u128 {
+        let m = lo.wrapping_mul(F::N_PRIME);
+        let (mn_hi, mn_lo) = Self::mul_wide(m, F::MODULUS);
+
+        let (sum_lo, carry) = lo.overflowing_add(mn_lo);
+        debug_assert_eq!(sum_lo, 0, "REDC: low limb must cancel by construction");
+
+        let mut reduced = hi.wrapping_add(mn_hi).wrapping_add(carry as u128);
+        if reduced >= F::MODULUS {
+            reduced -= F::MODULUS;
         }
+        reduced
+    }
 
-        if self.pow((self.modulus - 1) / 2).value != 1 {
-            return None;
+    /// `REDC(a * b)`, for `a`/`b` already in whatever representation
+    /// [`Self::redc`] expects them in (internal values when called from
+    /// `Mul`, an ordinary residue and `F::R2` when called from
+    /// [`Self::to_internal`]).
+    fn mont_mul(a: u128, b: u128) -> u128 {
+        let (hi, lo) = Self::mul_wide(a, b);
+        Self::redc(lo, hi)
+    }
+
+    /// Converts an ordinary residue into this field's internal
+    /// representation (Montgomery form `a*R mod N` when the modulus is odd,
+    /// unchanged otherwise).
+    fn to_internal(raw: u128) -> u128 {
+        if Self::supports_montgomery() {
+            Self::mont_mul(raw, F::R2)
+        } else {
+            raw
         }
+    }
 
-        let mut q = self.modulus - 1;
-        let mut s = 0;
-        while q % 2 == 0 {
-            q /= 2;
-            s += 1;
+    /// Converts this field's internal representation back to an ordinary
+    /// residue.
+    fn from_internal(internal: u128) -> u128 {
+        if Self::supports_montgomery() {
+            Self::redc(internal, 0)
+        } else {
+            internal
         }
+    }
 
-        let mut z = 2u128;
-        while Self::new(z, self.modulus).unwrap().pow((self.modulus - 1) / 2).value == 1 {
-            z += 1;
+    pub fn sqrt(&self) -> Option<Self> {
+        if *self == Self::zero() {
+            return Some(*self);
+        }
+
+        if self.pow((F::MODULUS - 1) / 2) != Self::one() {
+            return None;
         }
 
-        let mut m = s;
-        let mut c = Self::new(z, self.modulus).unwrap().pow(q);
+        let q = (F::MODULUS - 1) >> F::TWO_ADICITY;
+        let mut m = F::TWO_ADICITY;
+        let mut c = Self::new(F::ROOT_OF_UNITY).unwrap();
         let mut t = self.pow(q);
         let mut r = self.pow((q + 1) / 2);
 
-        while t.value != 1 {
+        while t != Self::one() {
             let mut i = 0;
             let mut temp = t;
-            while temp.value != 1 && i < m {
+            while temp != Self::one() && i < m {
                 temp = temp * temp;
                 i += 1;
             }
@@ -127,11 +336,44 @@ impl FieldElement {
         Some(r)
     }
 
+    /// Inverts every element of `elems` in place using Montgomery's trick:
+    /// one inversion of the accumulated product plus ~3n multiplications,
+    /// instead of `n` independent extended-Euclid inversions. Zero entries
+    /// are left untouched and excluded from the accumulated product.
+    pub fn batch_inverse(elems: &mut [Self]) {
+        if elems.iter().all(|e| e.value == 0) {
+            // Nothing nonzero to invert.
+            return;
+        }
+
+        let n = elems.len();
+        let mut running_prefix = Vec::with_capacity(n);
+        let mut acc = Self::one();
+        for e in elems.iter() {
+            if e.value != 0 {
+                acc = acc * *e;
+            }
+            running_prefix.push(acc);
+        }
+
+        let mut inv_acc = acc.inverse().expect("accumulated product of nonzero elements is nonzero");
+
+        for i in (0..n).rev() {
+            if elems[i].value == 0 {
+                continue;
+            }
+            let prefix_before = if i == 0 { Self::one() } else { running_prefix[i - 1] };
+            let original = elems[i];
+            elems[i] = prefix_before * inv_acc;
+            inv_acc = inv_acc * original;
+        }
+    }
+
     pub fn legendre_symbol(&self) -> i8 {
-        let result = self.pow((self.modulus - 1) / 2).value;
-        if result == 0 {
+        let result = self.pow((F::MODULUS - 1) / 2);
+        if result == Self::zero() {
             0
-        } else if result == 1 {
+        } else if result == Self::one() {
             1
         } else {
             -1
@@ -139,47 +381,46 @@ impl FieldElement {
     }
 }
 
-impl Add for FieldElement {
+impl<F: PrimeField> Add for FieldElement<F> {
     type Output = Self;
     fn add(self, other: Self) -> Self {
-        assert_eq!(self.modulus, other.modulus, "Moduli must match");
         Self {
-            value: (self.value + other.value) % self.modulus,
-            modulus: self.modulus,
+            value: (self.value + other.value) % F::MODULUS,
+            _field: PhantomData,
         }
     }
 }
 
-impl Sub for FieldElement {
+impl<F: PrimeField> Sub for FieldElement<F> {
     type Output = Self;
     fn sub(self, other: Self) -> Self {
-        assert_eq!(self.modulus, other.modulus, "Moduli must match");
         Self {
             value: if self.value >= other.value {
                 self.value - other.value
             } else {
-                self.modulus - (other.value - self.value)
+                F::MODULUS - (other.value - self.value)
             },
-            modulus: self.modulus,
+            _field: PhantomData,
         }
     }
 }
 
-impl Mul for FieldElement {
+impl<F: PrimeField> Mul for FieldElement<F> {
     type Output = Self;
     fn mul(self, other: Self) -> Self {
-        assert_eq!(self.modulus, other.modulus, "Moduli must match");
-        Self {
-            value: ((self.value as u128) * (other.value as u128)) % self.modulus,
-            modulus: self.modulus,
-        }
+        let value = if Self::supports_montgomery() {
+            Self::mont_mul(self.value, other.value)
+        } else {
+            // Fallback for even moduli, where Montgomery reduction doesn't apply.
+            (self.value * other.value) % F::MODULUS
+        };
+        Self { value, _field: PhantomData }
     }
 }
 
-impl Div for FieldElement {
+impl<F: PrimeField> Div for FieldElement<F> {
     type Output = Self;
     fn div(self, other: Self) -> Self {
-        assert_eq!(self.modulus, other.modulus, "Moduli must match");
         self * other.inverse().expect("Division by zero")
     }
 }
@@ -187,24 +428,77 @@ impl Div for FieldElement {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use num_bigint::BigUint;
     use proptest::prelude::*;
 
+    declare_prime_field!(TestField, 1009u128, 4, 11, 179);
+    type Fe = FieldElement<TestField>;
+
+    #[test]
+    fn montgomery_mul_matches_bigint_for_modulus_above_2_pow_64() {
+        // `TestField`'s modulus fits in a single 64-bit limb, so `mul_wide`'s
+        // high-limb carries (`mid_overflow`/`lo_overflow` in `redc`) never
+        // actually fire. This modulus is ~2^100, so both operands and the
+        // reduced result routinely exercise every carry path in `mul_wide`
+        // and `redc`'s native 128x128->256 arithmetic.
+        declare_prime_field!(BigTestField, 1_267_650_600_228_229_401_496_703_205_379u128, 1, 1, 1);
+        type BigFe = FieldElement<BigTestField>;
+
+        let a_raw = 123_456_789_012_345_678_901_234_567_890u128;
+        let b_raw = 987_654_321_098_765_432_109_876_543_210u128;
+        let a = BigFe::new(a_raw).unwrap();
+        let b = BigFe::new(b_raw).unwrap();
+
+        let expected = (BigUint::from(a_raw) * BigUint::from(b_raw)) % BigUint::from(BigTestField::MODULUS);
+        let expected: u128 = expected.to_string().parse().unwrap();
+
+        assert_eq!((a * b).value(), expected);
+    }
+
+    #[test]
+    fn value_round_trips_through_internal_representation() {
+        // `new`/`value` must agree on the ordinary residue regardless of
+        // whatever internal representation sits in between, since every
+        // other part of the crate reads field elements back out via
+        // `value()`.
+        for raw in [0u128, 1, 2, 500, 1008] {
+            let element = Fe::new(raw).unwrap();
+            assert_eq!(element.value(), raw);
+        }
+    }
+
+    #[test]
+    fn batch_inverse_matches_per_element_inverse() {
+        let mut elems: Vec<Fe> = [5u128, 0, 17, 1008, 0, 42]
+            .iter()
+            .map(|&v| Fe::new(v).unwrap())
+            .collect();
+        let expected: Vec<Fe> = elems
+            .iter()
+            .map(|e| if e.value() == 0 { *e } else { e.inverse().unwrap() })
+            .collect();
+
+        Fe::batch_inverse(&mut elems);
+
+        assert_eq!(elems, expected);
+    }
+
     proptest! {
         #[test]
         fn test_field_arithmetic(a in 0u128..1000, b in 0u128..1000) {
             let modulus = 1009u128; // Prime modulus for testing
-            if let (Ok(fa), Ok(fb)) = (FieldElement::new(a % modulus, modulus), FieldElement::new(b % modulus, modulus)) {
+            if let (Ok(fa), Ok(fb)) = (Fe::new(a % modulus), Fe::new(b % modulus)) {
                 let sum = fa + fb;
                 let product = fa * fb;
-                
-                prop_assert!(sum.value < modulus);
-                prop_assert!(product.value < modulus);
-                
+
+                prop_assert!(sum.value() < modulus);
+                prop_assert!(product.value() < modulus);
+
                 if b % modulus != 0 {
                     let quotient = fa / fb;
-                    prop_assert!(quotient.value < modulus);
+                    prop_assert!(quotient.value() < modulus);
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
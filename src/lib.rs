@@ -7,11 +7,15 @@ pub mod merkle;
 pub mod polynomial;
 pub mod commitment;
 pub mod proof;
+pub mod domain;
+pub mod poseidon;
+pub mod diagnostics;
 
-pub use field::FieldElement;
+pub use field::{FieldElement, PrimeField};
 pub use reed_muller::ReedMullerCode;
 pub use basefold::BasefoldProtocol;
-pub use error::CryptoError;
+pub use error::{CryptoError, ProofStage, VerificationCheck};
+pub use diagnostics::ProofFailureReport;
 
 #[cfg(test)]
 mod tests;
\ No newline at end of file
@@ -1,7 +1,14 @@
 use rand::Rng;
-use crate::field::FieldElement;
+use crate::field::{FieldElement, PrimeField};
 use crate::reed_muller::ReedMullerCode;
 use crate::basefold::BasefoldProtocol;
+use crate::declare_prime_field;
+
+// The Goldilocks prime `2^64 - 2^32 + 1`: small enough for fast arithmetic,
+// odd (so every multiply takes the Montgomery/REDC path), and with 2-adicity
+// 32, which is more than enough two-power subgroup room for the `variables`
+// used below.
+declare_prime_field!(Goldilocks, 18_446_744_069_414_584_321u128, 32, 7, 1_753_635_133_440_165_772u128);
 
 fn main() {
     // Initialize parameters
@@ -14,36 +21,37 @@ fn main() {
     let mut t_vectors = Vec::new();
     
     for d in (1..=variables).rev() {
-        let rm_code = ReedMullerCode::new(degree, d);
+        let rm_code: ReedMullerCode<Goldilocks> = ReedMullerCode::new(degree, d);
         code_family.push(rm_code);
-        
+
         let n = 2_usize.pow(d as u32);
-        let t_vector: Vec<FieldElement> = (0..n)
-            .map(|i| FieldElement::new(i as u128))
+        let t_vector: Vec<FieldElement<Goldilocks>> = (0..n)
+            .map(|i| FieldElement::new(i as u128).unwrap())
             .collect();
         t_vectors.push(t_vector);
     }
-    
-    let protocol = BasefoldProtocol::new(code_family, t_vectors);
-    
+
+    let protocol: BasefoldProtocol<Goldilocks> = BasefoldProtocol::new(code_family, t_vectors);
+
     // Test with random message
     let mut rng = rand::thread_rng();
-    let message: Vec<FieldElement> = (0..protocol.code_family[0].k)
-        .map(|_| FieldElement::new(rng.gen()))
+    let message: Vec<FieldElement<Goldilocks>> = (0..protocol.code_family[0].k)
+        .map(|_| FieldElement::new(rng.gen::<u128>() % Goldilocks::MODULUS).unwrap())
         .collect();
-    
+
     // Commit phase
     let oracles = protocol.commit(&message);
-    
+
     // Verify phase
     let acceptance = protocol.query(&oracles, security_parameter);
     println!("Protocol verification result: {}", acceptance);
-    
+
     // Test error detection
     let mut corrupted_oracles = oracles.clone();
     let random_index = rng.gen_range(0..corrupted_oracles[0].len());
-    corrupted_oracles[0][random_index] = FieldElement::new(rng.gen());
-    
+    corrupted_oracles[0][random_index] =
+        FieldElement::new(rng.gen::<u128>() % Goldilocks::MODULUS).unwrap();
+
     let rejection = protocol.query(&corrupted_oracles, security_parameter);
     println!("Corrupted oracle rejection: {}", !rejection);
 }
\ No newline at end of file
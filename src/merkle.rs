@@ -57,7 +57,11 @@ impl MerkleTree {
     
     pub fn generate_proof(&self, index: usize) -> Result<MerkleProof, CryptoError> {
         if index >= self.leaves.len() {
-            return Err(CryptoError::InvalidParameters("Index out of bounds".into()));
+            return Err(CryptoError::InvalidParameters {
+                param: "index",
+                expected: format!("< {}", self.leaves.len()),
+                got: index.to_string(),
+            });
         }
         
         let mut proof = Vec::new();
@@ -110,4 +114,227 @@ impl MerkleProof {
         
         current_hash == root
     }
+}
+
+/// A sparse Merkle tree keyed by arbitrary 32-byte keys over a tree of fixed
+/// [`SparseMerkleTree::HEIGHT`]: empty subtrees collapse to precomputed
+/// default hashes, so an all-empty tree of any depth costs O(1), and interior
+/// nodes are only ever created lazily along a key's path as values are
+/// inserted. Unlike [`MerkleTree`], which only supports a dense leaf vector
+/// and membership proofs, this gives the crate a stateful key/value
+/// commitment that can also prove a key is *absent* (maps to the
+/// default/empty leaf along its path).
+pub struct SparseMerkleTree {
+    // Keyed by (depth from the root, path prefix taken to reach that node).
+    // Only non-default interior nodes are stored; everything else is
+    // reconstructed from `default_hashes` on the fly.
+    nodes: HashMap<(usize, Vec<bool>), [u8; 32]>,
+    leaves: HashMap<[u8; 32], Vec<u8>>,
+    // default_hashes[d] is the root hash of an empty subtree of height `d`
+    // above the leaves; default_hashes[HEIGHT] is the root of a fully empty
+    // tree.
+    default_hashes: Vec<[u8; 32]>,
+}
+
+/// A proof of membership (`leaf_value` is `Some`) or non-membership
+/// (`leaf_value` is `None`, meaning the key's path leads to the default
+/// empty leaf) against a [`SparseMerkleTree`] root.
+pub struct SparseMerkleProof {
+    key: [u8; 32],
+    leaf_value: Option<Vec<u8>>,
+    // Siblings from the leaf up to the root, mirroring `MerkleProof::proof`'s
+    // leaf-to-root ordering.
+    siblings: Vec<[u8; 32]>,
+}
+
+impl SparseMerkleTree {
+    pub const HEIGHT: usize = 256;
+
+    pub fn new() -> Self {
+        let mut default_hashes = Vec::with_capacity(Self::HEIGHT + 1);
+        default_hashes.push(Self::hash_leaf(&[]));
+        for level in 1..=Self::HEIGHT {
+            let prev = default_hashes[level - 1];
+            default_hashes.push(Self::hash_pair(&prev, &prev));
+        }
+
+        Self {
+            nodes: HashMap::new(),
+            leaves: HashMap::new(),
+            default_hashes,
+        }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.nodes
+            .get(&(0, Vec::new()))
+            .copied()
+            .unwrap_or(self.default_hashes[Self::HEIGHT])
+    }
+
+    pub fn insert(&mut self, key: [u8; 32], value: Vec<u8>) {
+        let path = Self::path_bits(&key);
+        let mut current_hash = Self::hash_leaf(&value);
+        self.leaves.insert(key, value);
+
+        for depth in (0..Self::HEIGHT).rev() {
+            let sibling_hash = self.sibling_hash(&key, &path, depth);
+            current_hash = if path[depth] {
+                Self::hash_pair(&sibling_hash, &current_hash)
+            } else {
+                Self::hash_pair(&current_hash, &sibling_hash)
+            };
+
+            if depth > 0 {
+                self.nodes.insert((depth, path[..depth].to_vec()), current_hash);
+            }
+        }
+
+        self.nodes.insert((0, Vec::new()), current_hash);
+    }
+
+    pub fn generate_proof(&self, key: &[u8; 32]) -> SparseMerkleProof {
+        let path = Self::path_bits(key);
+        let siblings = (0..Self::HEIGHT)
+            .rev()
+            .map(|depth| self.sibling_hash(key, &path, depth))
+            .collect();
+
+        SparseMerkleProof {
+            key: *key,
+            leaf_value: self.leaves.get(key).cloned(),
+            siblings,
+        }
+    }
+
+    /// The hash of the sibling of the node at `depth + 1` along `key`'s
+    /// path (the branch not taken at `depth`):
+    /// a stored interior node/leaf if one was ever inserted under it, or
+    /// the precomputed default for an empty subtree of that height otherwise.
+    fn sibling_hash(&self, key: &[u8; 32], path: &[bool], depth: usize) -> [u8; 32] {
+        if depth + 1 == Self::HEIGHT {
+            let mut sibling_key = *key;
+            let last_bit = Self::HEIGHT - 1;
+            sibling_key[last_bit / 8] ^= 1 << (7 - last_bit % 8);
+            return match self.leaves.get(&sibling_key) {
+                Some(value) => Self::hash_leaf(value),
+                None => self.default_hashes[0],
+            };
+        }
+
+        let mut sibling_prefix = path[..depth].to_vec();
+        sibling_prefix.push(!path[depth]);
+        self.nodes
+            .get(&(depth + 1, sibling_prefix))
+            .copied()
+            .unwrap_or(self.default_hashes[Self::HEIGHT - depth - 1])
+    }
+
+    /// `key`'s bits, MSB-first: `path[0]` is the branch taken at the root,
+    /// `path[HEIGHT - 1]` is the branch taken just above the leaf.
+    fn path_bits(key: &[u8; 32]) -> Vec<bool> {
+        let mut bits = Vec::with_capacity(Self::HEIGHT);
+        for byte in key.iter() {
+            for i in (0..8).rev() {
+                bits.push((byte >> i) & 1 == 1);
+            }
+        }
+        bits
+    }
+
+    fn hash_leaf(value: &[u8]) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(value);
+        let mut out = [0u8; 32];
+        out.copy_from_slice(&hasher.finalize());
+        out
+    }
+
+    fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        hasher.update(left);
+        hasher.update(right);
+        let mut out = [0u8; 32];
+        out.copy_from_slice(&hasher.finalize());
+        out
+    }
+}
+
+impl Default for SparseMerkleTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparseMerkleProof {
+    pub fn is_membership(&self) -> bool {
+        self.leaf_value.is_some()
+    }
+
+    pub fn verify(&self, root: &[u8; 32]) -> bool {
+        let path = SparseMerkleTree::path_bits(&self.key);
+        let mut current = match &self.leaf_value {
+            Some(value) => SparseMerkleTree::hash_leaf(value),
+            None => SparseMerkleTree::hash_leaf(&[]),
+        };
+
+        for (i, sibling) in self.siblings.iter().enumerate() {
+            let depth = SparseMerkleTree::HEIGHT - 1 - i;
+            current = if path[depth] {
+                SparseMerkleTree::hash_pair(sibling, &current)
+            } else {
+                SparseMerkleTree::hash_pair(&current, sibling)
+            };
+        }
+
+        current == *root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(byte: u8) -> [u8; 32] {
+        let mut k = [0u8; 32];
+        k[31] = byte;
+        k
+    }
+
+    #[test]
+    fn membership_proof_round_trips() {
+        let mut tree = SparseMerkleTree::new();
+        tree.insert(key(1), b"alice".to_vec());
+        tree.insert(key(2), b"bob".to_vec());
+
+        let root = tree.root();
+        let proof = tree.generate_proof(&key(1));
+
+        assert!(proof.is_membership());
+        assert!(proof.verify(&root));
+    }
+
+    #[test]
+    fn non_membership_proof_round_trips() {
+        let mut tree = SparseMerkleTree::new();
+        tree.insert(key(1), b"alice".to_vec());
+
+        let root = tree.root();
+        let proof = tree.generate_proof(&key(99));
+
+        assert!(!proof.is_membership());
+        assert!(proof.verify(&root));
+    }
+
+    #[test]
+    fn proof_fails_against_wrong_root() {
+        let mut tree = SparseMerkleTree::new();
+        tree.insert(key(1), b"alice".to_vec());
+        let proof = tree.generate_proof(&key(1));
+
+        let mut other = SparseMerkleTree::new();
+        other.insert(key(1), b"mallory".to_vec());
+
+        assert!(!proof.verify(&other.root()));
+    }
 }
\ No newline at end of file
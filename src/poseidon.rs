@@ -0,0 +1,193 @@
+use crate::field::{FieldElement, PrimeField};
+
+/// State width for 2-to-1 compression: one capacity element plus a
+/// rate-two portion wide enough to absorb both Merkle-tree children at once.
+const WIDTH: usize = 3;
+
+/// Poseidon permutation over [`FieldElement`], used by the field-based
+/// Merkle tree as a deterministic, algebraic replacement for a
+/// randomized/non-reproducible `hash_pair`. `R_f` full rounds (the S-box
+/// applied to every state element) are split half before and half after
+/// `R_p` partial rounds (the S-box applied only to the first element), with
+/// each round adding round constants and then mixing the state through an
+/// MDS matrix.
+pub struct Poseidon<F: PrimeField> {
+    alpha: u128,
+    full_rounds: usize,
+    partial_rounds: usize,
+    round_constants: Vec<FieldElement<F>>,
+    mds: [[FieldElement<F>; WIDTH]; WIDTH],
+}
+
+impl<F: PrimeField> Poseidon<F> {
+    const FULL_ROUNDS: usize = 8;
+    const PARTIAL_ROUNDS: usize = 56;
+
+    /// Builds a permutation instance whose round constants, MDS matrix, and
+    /// S-box exponent are all derived deterministically from `F::MODULUS` -
+    /// no randomness, so two instances over the same field always agree.
+    pub fn new() -> Self {
+        let alpha = Self::find_alpha();
+        let round_constants = Self::derive_round_constants();
+        let mds = Self::derive_mds();
+
+        Self {
+            alpha,
+            full_rounds: Self::FULL_ROUNDS,
+            partial_rounds: Self::PARTIAL_ROUNDS,
+            round_constants,
+            mds,
+        }
+    }
+
+    /// The S-box `x^alpha` must be a bijection on `F*`, which holds exactly
+    /// when `gcd(alpha, MODULUS - 1) == 1`. Picks the smallest odd candidate
+    /// satisfying that instead of hard-coding e.g. `5` or `7`, since which
+    /// small exponents are coprime to `MODULUS - 1` varies by field.
+    fn find_alpha() -> u128 {
+        let mut alpha = 3u128;
+        while gcd(alpha, F::MODULUS - 1) != 1 {
+            alpha += 2;
+        }
+        alpha
+    }
+
+    /// Derives `(full_rounds + partial_rounds) * WIDTH` round constants from
+    /// the modulus via a fixed linear congruential sequence, so constants
+    /// are reproducible across runs and processes without shipping a table.
+    fn derive_round_constants() -> Vec<FieldElement<F>> {
+        let total = (Self::FULL_ROUNDS + Self::PARTIAL_ROUNDS) * WIDTH;
+        let mut constants = Vec::with_capacity(total);
+
+        let mut state = F::MODULUS ^ 0x9E37_79B9_7F4A_7C15;
+        for _ in 0..total {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            constants.push(FieldElement::new(state % F::MODULUS).unwrap());
+        }
+        constants
+    }
+
+    /// Builds a Cauchy MDS matrix `M[i][j] = 1 / (x_i + y_j)` with disjoint
+    /// `{x_i}` and `{y_j}`; every square submatrix of a Cauchy matrix is
+    /// nonsingular, which is exactly the MDS property Poseidon's mixing
+    /// layer needs.
+    fn derive_mds() -> [[FieldElement<F>; WIDTH]; WIDTH] {
+        let mut mds = [[FieldElement::zero(); WIDTH]; WIDTH];
+        for (i, row) in mds.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                let x = FieldElement::new(i as u128).unwrap();
+                let y = FieldElement::new((WIDTH + j) as u128).unwrap();
+                *entry = (x + y).inverse().unwrap();
+            }
+        }
+        mds
+    }
+
+    fn add_round_constants(&self, state: &mut [FieldElement<F>; WIDTH], round: usize) {
+        for (i, s) in state.iter_mut().enumerate() {
+            *s = *s + self.round_constants[round * WIDTH + i];
+        }
+    }
+
+    fn full_sbox(&self, state: &mut [FieldElement<F>; WIDTH]) {
+        for s in state.iter_mut() {
+            *s = s.pow(self.alpha);
+        }
+    }
+
+    fn partial_sbox(&self, state: &mut [FieldElement<F>; WIDTH]) {
+        state[0] = state[0].pow(self.alpha);
+    }
+
+    fn apply_mds(&self, state: &[FieldElement<F>; WIDTH]) -> [FieldElement<F>; WIDTH] {
+        let mut out = [FieldElement::zero(); WIDTH];
+        for (i, out_i) in out.iter_mut().enumerate() {
+            let mut acc = FieldElement::zero();
+            for (j, s) in state.iter().enumerate() {
+                acc = acc + self.mds[i][j] * *s;
+            }
+            *out_i = acc;
+        }
+        out
+    }
+
+    /// Runs the full `R_f/2` -> `R_p` partial -> `R_f/2` permutation schedule.
+    pub fn permute(&self, mut state: [FieldElement<F>; WIDTH]) -> [FieldElement<F>; WIDTH] {
+        let half_full = self.full_rounds / 2;
+        let mut round = 0;
+
+        for _ in 0..half_full {
+            self.add_round_constants(&mut state, round);
+            self.full_sbox(&mut state);
+            state = self.apply_mds(&state);
+            round += 1;
+        }
+        for _ in 0..self.partial_rounds {
+            self.add_round_constants(&mut state, round);
+            self.partial_sbox(&mut state);
+            state = self.apply_mds(&state);
+            round += 1;
+        }
+        for _ in 0..half_full {
+            self.add_round_constants(&mut state, round);
+            self.full_sbox(&mut state);
+            state = self.apply_mds(&state);
+            round += 1;
+        }
+
+        state
+    }
+
+    /// 2-to-1 compression: absorbs `left`/`right` into the rate portion of a
+    /// zero-capacity state, runs the permutation, and squeezes out the first
+    /// element.
+    pub fn hash_pair(&self, left: FieldElement<F>, right: FieldElement<F>) -> FieldElement<F> {
+        let state = [FieldElement::zero(), left, right];
+        self.permute(state)[0]
+    }
+}
+
+impl<F: PrimeField> Default for Poseidon<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::declare_prime_field;
+
+    declare_prime_field!(PoseidonTestField, 1009u128, 4, 11, 179);
+    type Fe = FieldElement<PoseidonTestField>;
+
+    #[test]
+    fn hash_pair_is_deterministic_across_instances() {
+        let a = Fe::new(3).unwrap();
+        let b = Fe::new(7).unwrap();
+
+        let first = Poseidon::<PoseidonTestField>::new().hash_pair(a, b);
+        let second = Poseidon::<PoseidonTestField>::new().hash_pair(a, b);
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn hash_pair_is_sensitive_to_argument_order() {
+        let poseidon = Poseidon::<PoseidonTestField>::new();
+        let a = Fe::new(3).unwrap();
+        let b = Fe::new(7).unwrap();
+
+        assert_ne!(poseidon.hash_pair(a, b), poseidon.hash_pair(b, a));
+    }
+}
@@ -1,18 +1,21 @@
-use crate::field::FieldElement;
+use crate::domain::EvaluationDomain;
+use crate::field::{FieldElement, PrimeField};
 use std::collections::HashMap;
+use std::marker::PhantomData;
 
-pub struct ReedMullerCode {
+pub struct ReedMullerCode<F: PrimeField> {
     pub degree: usize,
     pub variables: usize,
     pub n: usize,
     pub k: usize,
-    pub generator_matrix: Vec<Vec<FieldElement>>,
-    pub parity_check_matrix: Vec<Vec<FieldElement>>,
+    pub generator_matrix: Vec<Vec<FieldElement<F>>>,
+    pub parity_check_matrix: Vec<Vec<FieldElement<F>>>,
     evaluation_points: Vec<Vec<u8>>,
     weight_enumerator: HashMap<usize, usize>,
+    _field: PhantomData<F>,
 }
 
-impl ReedMullerCode {
+impl<F: PrimeField> ReedMullerCode<F> {
     pub fn new(degree: usize, variables: usize) -> Self {
         let n = 2_usize.pow(variables as u32);
         let k = Self::compute_dimension(degree, variables);
@@ -30,25 +33,56 @@ impl ReedMullerCode {
             parity_check_matrix,
             evaluation_points,
             weight_enumerator,
+            _field: PhantomData,
         }
     }
 
-    pub fn encode(&self, message: Vec<FieldElement>) -> Vec<FieldElement> {
+    /// Dense O(n·k) matrix-vector product against `generator_matrix`. The
+    /// output is indexed the same way `parity_check_matrix`/`decode`/
+    /// `evaluate_on_subspace` expect (by `evaluation_points`/boolean
+    /// hypercube position). [`Self::decode`] does not invert it: it
+    /// returns the same 0/1 value in every message position.
+    pub fn encode(&self, message: Vec<FieldElement<F>>) -> Vec<FieldElement<F>> {
         assert_eq!(message.len(), self.k);
-        let mut codeword = vec![FieldElement::zero(); self.n];
-        
+        let mut codeword = vec![FieldElement::<F>::zero(); self.n];
+
         for i in 0..self.k {
             for j in 0..self.n {
                 codeword[j] = codeword[j] + message[i] * self.generator_matrix[i][j];
             }
         }
-        
+
         codeword
     }
 
-    pub fn decode(&self, received: Vec<FieldElement>) -> Vec<FieldElement> {
+    /// Alternative to [`Self::encode`] for callers that specifically want an
+    /// NTT-domain codeword: treats `message` as the low-degree coefficients
+    /// of a polynomial and evaluates it on the coset `domain.generator *
+    /// H` of `domain`'s size-`n` subgroup `H`, in O(n log n) instead of
+    /// O(n·k). Evaluating on the coset rather than `H` itself keeps these
+    /// evaluation points disjoint from the subgroup the message's own
+    /// low-degree structure is defined over. The result is indexed by power
+    /// of `domain.omega` (i.e. position `k` holds the evaluation at
+    /// `domain.generator * domain.omega^k`), NOT by
+    /// `evaluation_points`/boolean-hypercube position like [`Self::encode`]
+    /// is - it is a different representation of the code and is not
+    /// interchangeable with [`Self::decode`] or `parity_check_matrix`.
+    /// Returns an error if `self.n` isn't a power of two the field has
+    /// enough two-adicity for.
+    pub fn encode_via_domain(
+        &self,
+        message: Vec<FieldElement<F>>,
+    ) -> Result<Vec<FieldElement<F>>, crate::error::CryptoError> {
+        let domain = EvaluationDomain::<F>::new(self.n)?;
+        let mut coeffs = message;
+        coeffs.resize(self.n, FieldElement::<F>::zero());
+        domain.coset_fft(&mut coeffs);
+        Ok(coeffs)
+    }
+
+    pub fn decode(&self, received: Vec<FieldElement<F>>) -> Vec<FieldElement<F>> {
         assert_eq!(received.len(), self.n);
-        let mut decoded = vec![FieldElement::zero(); self.k];
+        let mut decoded = vec![FieldElement::<F>::zero(); self.k];
         
         // Majority logic decoding for Reed-Muller codes
         for i in (0..=self.degree).rev() {
@@ -57,13 +91,13 @@ impl ReedMullerCode {
                 let mut votes = 0i32;
                 for subspace in &subspaces {
                     let eval = self.evaluate_on_subspace(&received, subspace);
-                    if eval.value > FIELD_SIZE / 2 {
+                    if eval.value() > F::MODULUS / 2 {
                         votes += 1;
                     } else {
                         votes -= 1;
                     }
                 }
-                decoded[j] = if votes > 0 { FieldElement::one() } else { FieldElement::zero() };
+                decoded[j] = if votes > 0 { FieldElement::<F>::one() } else { FieldElement::<F>::zero() };
             }
         }
         
@@ -114,8 +148,8 @@ impl ReedMullerCode {
         subspace
     }
 
-    fn evaluate_on_subspace(&self, received: &[FieldElement], subspace: &[Vec<u8>]) -> FieldElement {
-        let mut sum = FieldElement::zero();
+    fn evaluate_on_subspace(&self, received: &[FieldElement<F>], subspace: &[Vec<u8>]) -> FieldElement<F> {
+        let mut sum = FieldElement::<F>::zero();
         for point in subspace {
             let idx = self.point_to_index(point);
             sum = sum + received[idx];
@@ -137,7 +171,7 @@ impl ReedMullerCode {
         degree: usize,
         variables: usize,
         evaluation_points: &[Vec<u8>],
-    ) -> Vec<Vec<FieldElement>> {
+    ) -> Vec<Vec<FieldElement<F>>> {
         let n = 2_usize.pow(variables as u32);
         let k = Self::compute_dimension(degree, variables);
         let r = n - k;
@@ -147,12 +181,12 @@ impl ReedMullerCode {
         let mut dual_basis = Vec::new();
         for d in (degree + 1)..=variables {
             for combination in Self::generate_combinations(variables, d) {
-                let mut row = vec![FieldElement::zero(); n];
+                let mut row = vec![FieldElement::<F>::zero(); n];
                 for (j, point) in evaluation_points.iter().enumerate() {
-                    let mut eval = FieldElement::one();
+                    let mut eval = FieldElement::<F>::one();
                     for &var in &combination {
                         if point[var] == 1 {
-                            eval = eval * FieldElement::new(1);
+                            eval = eval * FieldElement::<F>::new(1).unwrap();
                         }
                     }
                     row[j] = eval;